@@ -0,0 +1,214 @@
+//! Admin accounts and session tokens.
+//!
+//! Credentials are hashed with argon2id. A session token is an HMAC over
+//! `user_id || issued_at || expiry`, so the server can validate it
+//! statelessly without keeping a session table.
+
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use argon2::{
+    password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Argon2,
+};
+use axum::{
+    extract::{FromRef, FromRequestParts},
+    http::{request::Parts, StatusCode},
+    Json,
+};
+use hmac::{Hmac, Mac};
+use serde::Serialize;
+use sha2::Sha256;
+
+const SESSION_LIFETIME_SECS: u64 = 3600;
+
+/// An admin account, identified by `user_id`, with an argon2id password hash.
+pub struct Account {
+    pub user_id: String,
+    password_hash: String,
+}
+
+impl Account {
+    /// Hashes `password` with argon2id to create a new account.
+    pub fn new(user_id: impl Into<String>, password: &str) -> Self {
+        let salt = SaltString::generate(&mut rand::thread_rng());
+        let password_hash = Argon2::default()
+            .hash_password(password.as_bytes(), &salt)
+            .expect("argon2 hashing")
+            .to_string();
+        Self { user_id: user_id.into(), password_hash }
+    }
+
+    fn verify(&self, password: &str) -> bool {
+        let Ok(parsed) = PasswordHash::new(&self.password_hash) else { return false };
+        Argon2::default().verify_password(password.as_bytes(), &parsed).is_ok()
+    }
+}
+
+/// The signing key used to mint and validate session tokens.
+pub struct SessionSigner {
+    key: Vec<u8>,
+    accounts: Vec<Account>,
+}
+
+impl SessionSigner {
+    pub fn new(key: Vec<u8>, accounts: Vec<Account>) -> Self {
+        Self { key, accounts }
+    }
+
+    /// Verifies `user_id`/`password` against the configured accounts and, on
+    /// success, issues a signed, expiring session token.
+    pub fn login(&self, user_id: &str, password: &str) -> Option<String> {
+        let account = self.accounts.iter().find(|a| a.user_id == user_id)?;
+        if !account.verify(password) {
+            return None;
+        }
+        let issued_at = now_secs();
+        let expiry = issued_at + SESSION_LIFETIME_SECS;
+        Some(self.sign(user_id, issued_at, expiry))
+    }
+
+    /// Validates `token`'s signature and expiry, returning the `user_id` it
+    /// was issued for.
+    pub fn validate(&self, token: &str) -> Option<String> {
+        let (payload, sig_hex) = token.rsplit_once('.')?;
+        let (user_id, issued_at, expiry) = parse_payload(payload)?;
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.key).ok()?;
+        mac.update(payload.as_bytes());
+        let expected = hex::decode(sig_hex).ok()?;
+        mac.verify_slice(&expected).ok()?;
+
+        let _ = issued_at;
+        if now_secs() >= expiry {
+            return None;
+        }
+        Some(user_id)
+    }
+
+    fn sign(&self, user_id: &str, issued_at: u64, expiry: u64) -> String {
+        let payload = format!("{user_id}|{issued_at}|{expiry}");
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.key).expect("hmac key");
+        mac.update(payload.as_bytes());
+        let sig = hex::encode(mac.finalize().into_bytes());
+        format!("{payload}.{sig}")
+    }
+}
+
+fn parse_payload(payload: &str) -> Option<(String, u64, u64)> {
+    let mut parts = payload.splitn(3, '|');
+    let user_id = parts.next()?.to_string();
+    let issued_at: u64 = parts.next()?.parse().ok()?;
+    let expiry: u64 = parts.next()?.parse().ok()?;
+    Some((user_id, issued_at, expiry))
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).expect("system clock before epoch").as_secs()
+}
+
+#[derive(Serialize)]
+pub struct AuthErrorBody {
+    pub code: &'static str,
+    pub detail: String,
+}
+
+fn unauthorized(detail: &str) -> (StatusCode, Json<AuthErrorBody>) {
+    (
+        StatusCode::UNAUTHORIZED,
+        Json(AuthErrorBody { code: "unauthorized", detail: detail.to_string() }),
+    )
+}
+
+/// Extractor that gates a handler behind a valid `Authorization: Bearer
+/// <token>` session token. Add this as a parameter to require login.
+pub struct AdminUser {
+    pub user_id: String,
+}
+
+#[axum::async_trait]
+impl<S> FromRequestParts<S> for AdminUser
+where
+    Arc<SessionSigner>: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = (StatusCode, Json<AuthErrorBody>);
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let header = parts
+            .headers
+            .get(axum::http::header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .ok_or_else(|| unauthorized("missing Authorization header"))?;
+
+        let token = header
+            .strip_prefix("Bearer ")
+            .ok_or_else(|| unauthorized("Authorization header must be a Bearer token"))?;
+
+        let signer = Arc::<SessionSigner>::from_ref(state);
+        let user_id = signer
+            .validate(token)
+            .ok_or_else(|| unauthorized("session token is invalid or expired"))?;
+
+        Ok(AdminUser { user_id })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signer() -> SessionSigner {
+        SessionSigner::new(b"test-signing-key".to_vec(), vec![Account::new("admin", "hunter2")])
+    }
+
+    #[test]
+    fn login_rejects_unknown_user() {
+        assert!(signer().login("nobody", "hunter2").is_none());
+    }
+
+    #[test]
+    fn login_rejects_wrong_password() {
+        assert!(signer().login("admin", "wrong-password").is_none());
+    }
+
+    #[test]
+    fn login_then_validate_round_trips() {
+        let signer = signer();
+        let token = signer.login("admin", "hunter2").expect("valid credentials");
+        assert_eq!(signer.validate(&token), Some("admin".to_string()));
+    }
+
+    #[test]
+    fn validate_rejects_tampered_signature() {
+        let signer = signer();
+        let token = signer.login("admin", "hunter2").expect("valid credentials");
+        let (payload, _sig) = token.rsplit_once('.').unwrap();
+        let tampered = format!("{payload}.{}", "0".repeat(64));
+
+        assert!(signer.validate(&tampered).is_none());
+    }
+
+    #[test]
+    fn validate_rejects_tampered_payload() {
+        let signer = signer();
+        let token = signer.login("admin", "hunter2").expect("valid credentials");
+        let (_payload, sig) = token.rsplit_once('.').unwrap();
+        let tampered = format!("attacker|0|9999999999.{sig}");
+
+        assert!(signer.validate(&tampered).is_none());
+    }
+
+    #[test]
+    fn validate_rejects_expired_token() {
+        let signer = signer();
+        let expired = signer.sign("admin", 0, 1);
+
+        assert!(signer.validate(&expired).is_none());
+    }
+
+    #[test]
+    fn validate_rejects_malformed_token() {
+        assert!(signer().validate("not-a-token").is_none());
+    }
+}
@@ -0,0 +1,198 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+
+use std::sync::Arc;
+
+use crate::accounts::{AdminUser, AuthErrorBody, SessionSigner};
+use crate::merkle::{self, Direction};
+use crate::peers::PeerRecord;
+use crate::state::NodeHandle;
+
+/// Upper bound on a submitted transaction's decoded payload size.
+const MAX_TX_BYTES: usize = 64 * 1024;
+
+#[derive(Serialize)]
+pub struct Status {
+    pub tip_height: u64,
+    pub mempool_len: usize,
+    pub state_root_hex: String,
+    pub peers: Vec<PeerRecord>,
+}
+
+pub async fn status(State(state): State<NodeHandle>) -> Json<Status> {
+    let node = state.lock().unwrap();
+    Json(Status {
+        tip_height: node.tip_height,
+        mempool_len: node.mempool.len(),
+        state_root_hex: hex::encode(&node.state_root),
+        peers: node.peers.clone(),
+    })
+}
+
+#[derive(Serialize)]
+pub struct MempoolResponse {
+    pub mempool_hex: Vec<String>,
+}
+
+pub async fn mempool(State(state): State<NodeHandle>) -> Json<MempoolResponse> {
+    let node = state.lock().unwrap();
+    Json(MempoolResponse { mempool_hex: node.mempool.iter().map(hex::encode).collect() })
+}
+
+#[derive(Serialize)]
+pub struct ProofStepHex {
+    pub sibling_hex: String,
+    pub direction: &'static str,
+}
+
+#[derive(Serialize)]
+pub struct ProofResponse {
+    pub index: usize,
+    pub leaf_hex: String,
+    pub proof: Vec<ProofStepHex>,
+}
+
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub struct ErrorBody {
+    pub code: &'static str,
+    pub detail: String,
+}
+
+pub async fn proof(
+    State(state): State<NodeHandle>,
+    Path(index): Path<usize>,
+) -> Result<Json<ProofResponse>, (StatusCode, Json<ErrorBody>)> {
+    let node = state.lock().unwrap();
+    let leaves: Vec<&[u8]> = node.mempool.iter().map(|m| m.as_slice()).collect();
+
+    let proof = merkle::merkle_proof(&leaves, index).ok_or_else(|| {
+        (
+            StatusCode::NOT_FOUND,
+            Json(ErrorBody {
+                code: "index_out_of_range",
+                detail: format!("no mempool entry at index {index}"),
+            }),
+        )
+    })?;
+
+    Ok(Json(ProofResponse {
+        index,
+        leaf_hex: hex::encode(&node.mempool[index]),
+        proof: proof
+            .into_iter()
+            .map(|step| ProofStepHex {
+                sibling_hex: hex::encode(step.sibling),
+                direction: match step.direction {
+                    Direction::Left => "left",
+                    Direction::Right => "right",
+                },
+            })
+            .collect(),
+    }))
+}
+
+#[derive(Deserialize)]
+pub struct TxSubmission {
+    pub payload_hex: String,
+}
+
+#[derive(Serialize)]
+pub struct TxResponse {
+    pub index: usize,
+    pub state_root_hex: String,
+}
+
+/// Decodes and validates a submitted transaction's `payload_hex`, split out
+/// from the handler so the hex/empty/oversize rules are unit-testable
+/// without going through axum's extractors.
+fn decode_tx_payload(payload_hex: &str) -> Result<Vec<u8>, ErrorBody> {
+    let payload = hex::decode(payload_hex).map_err(|e| ErrorBody {
+        code: "invalid_hex",
+        detail: format!("payload_hex is not valid hex: {e}"),
+    })?;
+
+    if payload.is_empty() {
+        return Err(ErrorBody { code: "empty_payload", detail: "payload must not be empty".to_string() });
+    }
+    if payload.len() > MAX_TX_BYTES {
+        return Err(ErrorBody {
+            code: "payload_too_large",
+            detail: format!("payload exceeds {MAX_TX_BYTES} byte limit"),
+        });
+    }
+
+    Ok(payload)
+}
+
+pub async fn submit_tx(
+    State(state): State<NodeHandle>,
+    _admin: AdminUser,
+    Json(tx): Json<TxSubmission>,
+) -> Result<Json<TxResponse>, (StatusCode, Json<ErrorBody>)> {
+    let payload = decode_tx_payload(&tx.payload_hex).map_err(|e| (StatusCode::BAD_REQUEST, Json(e)))?;
+
+    let mut node = state.lock().unwrap();
+    let index = node.push_tx(payload);
+    Ok(Json(TxResponse { index, state_root_hex: hex::encode(&node.state_root) }))
+}
+
+#[derive(Deserialize)]
+pub struct LoginRequest {
+    pub user_id: String,
+    pub password: String,
+}
+
+#[derive(Serialize)]
+pub struct LoginResponse {
+    pub token: String,
+}
+
+pub async fn login(
+    State(signer): State<Arc<SessionSigner>>,
+    Json(req): Json<LoginRequest>,
+) -> Result<Json<LoginResponse>, (StatusCode, Json<AuthErrorBody>)> {
+    let token = signer.login(&req.user_id, &req.password).ok_or_else(|| {
+        (
+            StatusCode::UNAUTHORIZED,
+            Json(AuthErrorBody {
+                code: "invalid_credentials",
+                detail: "user_id/password did not match".to_string(),
+            }),
+        )
+    })?;
+    Ok(Json(LoginResponse { token }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_non_hex_payload() {
+        let err = decode_tx_payload("not-hex").unwrap_err();
+        assert_eq!(err.code, "invalid_hex");
+    }
+
+    #[test]
+    fn rejects_empty_payload() {
+        let err = decode_tx_payload("").unwrap_err();
+        assert_eq!(err.code, "empty_payload");
+    }
+
+    #[test]
+    fn rejects_oversized_payload() {
+        let oversized = "ab".repeat(MAX_TX_BYTES + 1);
+        let err = decode_tx_payload(&oversized).unwrap_err();
+        assert_eq!(err.code, "payload_too_large");
+    }
+
+    #[test]
+    fn accepts_well_formed_payload() {
+        let payload = decode_tx_payload("deadbeef").expect("valid hex payload");
+        assert_eq!(payload, vec![0xde, 0xad, 0xbe, 0xef]);
+    }
+}
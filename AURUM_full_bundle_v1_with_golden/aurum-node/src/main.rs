@@ -1,20 +1,97 @@
-use aurum_pentest::{merkle::{leaf_hash, merkle_root}};
-use serde::Serialize;
-use tiny_http::{Response, Server};
-
-#[derive(Serialize, Default)]
-struct Status{ tip_height: u64, mempool_len: usize, state_root_hex: String }
-
-fn main(){
-  let height=0u64; let mempool=vec![b"demo-tx-1".to_vec(), b"demo-tx-2".to_vec()];
-  let root = merkle_root(&mempool.iter().map(|m| m.as_slice()).collect::<Vec<_>>()).unwrap();
-  let status = Status{ tip_height: height, mempool_len: mempool.len(), state_root_hex: hex::encode(root)};
-  let server=Server::http("0.0.0.0:8080").expect("bind");
-  println!("AURUM node on http://localhost:8080  (GET /status)");
-  for request in server.incoming_requests(){
-    match (request.method().as_str(), request.url()){
-      ("GET","/status")=>{ let body=serde_json::to_string(&status).unwrap(); let resp=Response::from_string(body).with_header("Content-Type: application/json".parse().unwrap()); let _=request.respond(resp); },
-      _=>{ let _=request.respond(Response::from_string("Not Found").with_status_code(404)); }
-    }
-  }
+mod accounts;
+mod handlers;
+mod merkle;
+mod peers;
+mod state;
+
+use std::sync::{Arc, Mutex};
+
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+use rand::Rng;
+
+use accounts::{Account, SessionSigner};
+use state::{AppState, NodeState};
+
+/// Peer base URLs this node gossips with, read from the comma-separated
+/// `AURUM_PEERS` env var (empty when unset).
+fn configured_peers() -> Vec<String> {
+    std::env::var("AURUM_PEERS")
+        .ok()
+        .map(|v| v.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect())
+        .unwrap_or_default()
+}
+
+fn random_alphanumeric(len: usize) -> String {
+    rand::thread_rng()
+        .sample_iter(&rand::distributions::Alphanumeric)
+        .take(len)
+        .map(char::from)
+        .collect()
+}
+
+/// Builds the session signer from env config. A missing `AURUM_SESSION_SECRET`
+/// or `AURUM_ADMIN_PASSWORD` would otherwise make auth fail open (anyone can
+/// compute an HMAC over a known secret, and a well-known default password is
+/// no login at all), so both are generated randomly per process instead of
+/// falling back to a literal default, and the generated password is printed
+/// once so the operator can actually use it.
+fn build_signer() -> SessionSigner {
+    let key = match std::env::var("AURUM_SESSION_SECRET") {
+        Ok(secret) => secret.into_bytes(),
+        Err(_) => {
+            eprintln!(
+                "WARNING: AURUM_SESSION_SECRET is not set; generating a random per-process \
+                 secret. Existing session tokens will be invalidated on restart. Set \
+                 AURUM_SESSION_SECRET to a stable value for production deployments."
+            );
+            random_alphanumeric(32).into_bytes()
+        }
+    };
+
+    let admin_password = match std::env::var("AURUM_ADMIN_PASSWORD") {
+        Ok(password) => password,
+        Err(_) => {
+            let generated = random_alphanumeric(20);
+            eprintln!(
+                "WARNING: AURUM_ADMIN_PASSWORD is not set; generated a random admin password \
+                 for this process: {generated}. Set AURUM_ADMIN_PASSWORD to avoid a new \
+                 password on every restart."
+            );
+            generated
+        }
+    };
+
+    let accounts = vec![Account::new("admin", &admin_password)];
+    SessionSigner::new(key, accounts)
+}
+
+#[tokio::main]
+async fn main() {
+    let mempool = vec![b"demo-tx-1".to_vec(), b"demo-tx-2".to_vec()];
+    let node = Arc::new(Mutex::new(NodeState::new(0, mempool)));
+    let auth = Arc::new(build_signer());
+
+    peers::spawn_peer_sync(node.clone(), configured_peers());
+
+    let state = AppState { node, auth };
+
+    let app = Router::new()
+        .route("/status", get(handlers::status))
+        .route("/proof/:index", get(handlers::proof))
+        .route("/mempool", get(handlers::mempool))
+        .route("/tx", post(handlers::submit_tx))
+        .route("/login", post(handlers::login))
+        .with_state(state);
+
+    println!(
+        "AURUM node on http://localhost:8080  (GET /status, GET /proof/:index, GET /mempool, POST /tx [auth], POST /login)"
+    );
+    axum::Server::bind(&"0.0.0.0:8080".parse().unwrap())
+        .serve(app.into_make_service())
+        .await
+        .expect("server error");
 }
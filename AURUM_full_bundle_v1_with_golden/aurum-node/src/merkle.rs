@@ -0,0 +1,123 @@
+//! Merkle tree helpers shared by the status/proof endpoints.
+//!
+//! Mirrors the hashing rules of `aurum_pentest::merkle`: leaves are hashed
+//! with [`leaf_hash`], and internal levels duplicate the last node when the
+//! level has an odd count, exactly as [`merkle_root`] does.
+
+pub use aurum_pentest::merkle::{leaf_hash, merkle_root};
+
+/// Which side of the running hash a proof step's sibling sits on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+/// One step of an inclusion proof: a sibling hash and which side it's on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProofStep {
+    pub sibling: Vec<u8>,
+    pub direction: Direction,
+}
+
+pub type MerkleProof = Vec<ProofStep>;
+
+/// Builds an inclusion proof for `leaves[index]`, or `None` if `index` is
+/// out of range. Follows the same level-by-level construction as
+/// [`merkle_root`], duplicating the last node of an odd-sized level.
+pub fn merkle_proof(leaves: &[&[u8]], index: usize) -> Option<MerkleProof> {
+    if index >= leaves.len() {
+        return None;
+    }
+
+    let mut level: Vec<Vec<u8>> = leaves.iter().map(|l| leaf_hash(l)).collect();
+    let mut i = index;
+    let mut proof = Vec::new();
+
+    while level.len() > 1 {
+        if level.len() % 2 == 1 {
+            level.push(level.last().unwrap().clone());
+        }
+
+        let sibling_index = i ^ 1;
+        let direction = if sibling_index < i { Direction::Left } else { Direction::Right };
+        proof.push(ProofStep { sibling: level[sibling_index].clone(), direction });
+
+        level = level
+            .chunks(2)
+            .map(|pair| combine(&pair[0], &pair[1]))
+            .collect();
+        i /= 2;
+    }
+
+    Some(proof)
+}
+
+/// Recomputes the root from `leaf` and `proof`, and compares it to `root`.
+pub fn verify_proof(leaf: &[u8], proof: &MerkleProof, root: &[u8]) -> bool {
+    let mut running = leaf_hash(leaf);
+    for step in proof {
+        running = match step.direction {
+            Direction::Left => combine(&step.sibling, &running),
+            Direction::Right => combine(&running, &step.sibling),
+        };
+    }
+    running == root
+}
+
+fn combine(left: &[u8], right: &[u8]) -> Vec<u8> {
+    leaf_hash(&[left, right].concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaves(n: usize) -> Vec<Vec<u8>> {
+        (0..n).map(|i| format!("leaf-{i}").into_bytes()).collect()
+    }
+
+    fn round_trips(n: usize) {
+        let owned = leaves(n);
+        let refs: Vec<&[u8]> = owned.iter().map(|l| l.as_slice()).collect();
+        let root = merkle_root(&refs).unwrap();
+
+        for index in 0..n {
+            let proof = merkle_proof(&refs, index).expect("index is in range");
+            assert!(
+                verify_proof(&owned[index], &proof, &root),
+                "proof for index {index} of {n} leaves did not verify"
+            );
+        }
+    }
+
+    #[test]
+    fn round_trips_for_various_leaf_counts() {
+        for n in [1, 2, 3, 4, 5, 7, 8] {
+            round_trips(n);
+        }
+    }
+
+    #[test]
+    fn empty_tree_has_no_valid_index() {
+        let refs: Vec<&[u8]> = Vec::new();
+        assert_eq!(merkle_proof(&refs, 0), None);
+    }
+
+    #[test]
+    fn out_of_range_index_returns_none() {
+        let owned = leaves(3);
+        let refs: Vec<&[u8]> = owned.iter().map(|l| l.as_slice()).collect();
+        assert_eq!(merkle_proof(&refs, 3), None);
+    }
+
+    #[test]
+    fn tampered_leaf_fails_verification() {
+        let owned = leaves(5);
+        let refs: Vec<&[u8]> = owned.iter().map(|l| l.as_slice()).collect();
+        let root = merkle_root(&refs).unwrap();
+        let proof = merkle_proof(&refs, 2).unwrap();
+
+        assert!(!verify_proof(b"not-the-real-leaf", &proof, &root));
+    }
+}
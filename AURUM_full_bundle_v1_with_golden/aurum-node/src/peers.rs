@@ -0,0 +1,156 @@
+//! Peer gossip: periodically pulls each configured peer's advertised state
+//! root and independently re-derives it from the peer's own mempool before
+//! trusting it.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::merkle::merkle_root;
+use crate::state::NodeHandle;
+
+const SYNC_INTERVAL: Duration = Duration::from_secs(30);
+const PEER_REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// What we currently believe about a configured peer.
+#[derive(Clone, Serialize)]
+pub struct PeerRecord {
+    pub base_url: String,
+    pub last_seen_height: Option<u64>,
+    pub verified: bool,
+}
+
+impl PeerRecord {
+    fn unverified(base_url: String) -> Self {
+        Self { base_url, last_seen_height: None, verified: false }
+    }
+}
+
+#[derive(Deserialize)]
+struct RemoteStatus {
+    tip_height: u64,
+    state_root_hex: String,
+}
+
+#[derive(Deserialize)]
+struct RemoteMempool {
+    mempool_hex: Vec<String>,
+}
+
+/// Spawns the background task that gossips with `peer_urls` forever.
+pub fn spawn_peer_sync(state: NodeHandle, peer_urls: Vec<String>) {
+    {
+        let mut node = state.lock().unwrap();
+        node.peers = peer_urls.iter().cloned().map(PeerRecord::unverified).collect();
+    }
+
+    tokio::spawn(async move {
+        let client = reqwest::Client::builder()
+            .timeout(PEER_REQUEST_TIMEOUT)
+            .build()
+            .expect("reqwest client");
+        let mut ticker = tokio::time::interval(SYNC_INTERVAL);
+        loop {
+            ticker.tick().await;
+            for base_url in &peer_urls {
+                let mut node = state.lock().unwrap();
+                let previous_height =
+                    node.peers.iter().find(|p| &p.base_url == base_url).and_then(|p| p.last_seen_height);
+                drop(node);
+
+                let record = sync_peer(&client, base_url, previous_height).await;
+
+                let mut node = state.lock().unwrap();
+                if let Some(slot) = node.peers.iter_mut().find(|p| &p.base_url == base_url) {
+                    *slot = record;
+                }
+            }
+        }
+    });
+}
+
+/// Fetches one peer's status and mempool, and verifies the advertised root
+/// against a root recomputed locally from the peer's own transactions. On
+/// fetch failure (including a timed-out request), keeps `previous_height` as
+/// the last successful observation instead of discarding it, and only flips
+/// `verified` to `false`.
+async fn sync_peer(
+    client: &reqwest::Client,
+    base_url: &str,
+    previous_height: Option<u64>,
+) -> PeerRecord {
+    let fetch = async {
+        let status: RemoteStatus =
+            client.get(format!("{base_url}/status")).send().await?.json().await?;
+        let mempool: RemoteMempool =
+            client.get(format!("{base_url}/mempool")).send().await?.json().await?;
+        Ok::<_, reqwest::Error>((status, mempool))
+    };
+
+    match fetch.await {
+        Ok((status, mempool)) => PeerRecord {
+            base_url: base_url.to_string(),
+            last_seen_height: Some(status.tip_height),
+            verified: verify_advertised_root(&mempool.mempool_hex, &status.state_root_hex),
+        },
+        Err(_) => PeerRecord {
+            base_url: base_url.to_string(),
+            last_seen_height: previous_height,
+            verified: false,
+        },
+    }
+}
+
+/// Recomputes `merkle_root` over a peer's own mempool and checks it against
+/// the root the peer advertised, so a peer can't be trusted on its word
+/// alone. Split out from [`sync_peer`] so it's unit-testable without a
+/// network round trip.
+fn verify_advertised_root(mempool_hex: &[String], claimed_root_hex: &str) -> bool {
+    let txs: Vec<Vec<u8>> = mempool_hex.iter().filter_map(|h| hex::decode(h).ok()).collect();
+    let leaves: Vec<&[u8]> = txs.iter().map(|t| t.as_slice()).collect();
+    let recomputed = merkle_root(&leaves).unwrap_or_default();
+    hex::encode(&recomputed) == claimed_root_hex
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_root_that_matches_recomputation() {
+        let mempool_hex = vec![hex::encode("demo-tx-1"), hex::encode("demo-tx-2")];
+        let leaves: Vec<&[u8]> = vec![b"demo-tx-1", b"demo-tx-2"];
+        let root_hex = hex::encode(merkle_root(&leaves).unwrap());
+
+        assert!(verify_advertised_root(&mempool_hex, &root_hex));
+    }
+
+    #[test]
+    fn rejects_root_that_does_not_match_recomputation() {
+        let mempool_hex = vec![hex::encode("demo-tx-1"), hex::encode("demo-tx-2")];
+
+        assert!(!verify_advertised_root(&mempool_hex, "not-the-real-root"));
+    }
+
+    #[test]
+    fn rejects_root_for_empty_mempool_claiming_nonempty_root() {
+        let real_leaves: Vec<&[u8]> = vec![b"demo-tx-1"];
+        let claimed_root_hex = hex::encode(merkle_root(&real_leaves).unwrap());
+
+        assert!(!verify_advertised_root(&[], &claimed_root_hex));
+    }
+
+    #[tokio::test]
+    async fn fetch_failure_preserves_previous_height_and_clears_verified() {
+        let client = reqwest::Client::builder()
+            .timeout(PEER_REQUEST_TIMEOUT)
+            .build()
+            .expect("reqwest client");
+
+        // Nothing listens on this port, so the fetch fails immediately.
+        let record = sync_peer(&client, "http://127.0.0.1:1", Some(42)).await;
+
+        assert_eq!(record.last_seen_height, Some(42));
+        assert!(!record.verified);
+    }
+}
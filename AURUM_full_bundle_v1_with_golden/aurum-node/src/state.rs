@@ -0,0 +1,62 @@
+use std::sync::{Arc, Mutex};
+
+use axum::extract::FromRef;
+
+use crate::accounts::SessionSigner;
+use crate::merkle::merkle_root;
+use crate::peers::PeerRecord;
+
+/// Mutable node state shared across all request handlers.
+pub struct NodeState {
+    pub tip_height: u64,
+    pub mempool: Vec<Vec<u8>>,
+    pub state_root: Vec<u8>,
+    pub peers: Vec<PeerRecord>,
+}
+
+impl NodeState {
+    pub fn new(tip_height: u64, mempool: Vec<Vec<u8>>) -> Self {
+        let state_root = recompute_root(&mempool);
+        Self { tip_height, mempool, state_root, peers: Vec::new() }
+    }
+
+    /// Recomputes `state_root` from the current mempool contents.
+    pub fn refresh_root(&mut self) {
+        self.state_root = recompute_root(&self.mempool);
+    }
+
+    /// Appends `payload` to the mempool, refreshes `state_root`, and returns
+    /// the index the transaction was assigned.
+    pub fn push_tx(&mut self, payload: Vec<u8>) -> usize {
+        self.mempool.push(payload);
+        self.refresh_root();
+        self.mempool.len() - 1
+    }
+}
+
+fn recompute_root(mempool: &[Vec<u8>]) -> Vec<u8> {
+    let leaves: Vec<&[u8]> = mempool.iter().map(|m| m.as_slice()).collect();
+    merkle_root(&leaves).unwrap_or_default()
+}
+
+pub type NodeHandle = Arc<Mutex<NodeState>>;
+
+/// Combined axum state: node state plus the auth signer, split out so
+/// handlers can extract just the piece they need.
+#[derive(Clone)]
+pub struct AppState {
+    pub node: NodeHandle,
+    pub auth: Arc<SessionSigner>,
+}
+
+impl FromRef<AppState> for NodeHandle {
+    fn from_ref(state: &AppState) -> Self {
+        state.node.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<SessionSigner> {
+    fn from_ref(state: &AppState) -> Self {
+        state.auth.clone()
+    }
+}